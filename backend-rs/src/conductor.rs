@@ -1,35 +1,170 @@
 use pyo3::prelude::*;
 
-// TODO: Implement full conductor logic
-// This will handle:
-// - Queue processing loop
-// - City sync scheduling
-// - Meeting processing orchestration
+use std::collections::{HashMap, VecDeque};
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Arc, Condvar, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
 
+use crate::pdf::PdfExtractor;
+use crate::rate_limiter::RateLimiter;
+
+const DEFAULT_WORKER_COUNT: usize = 4;
+const DEFAULT_REDIS_URL: &str = "redis://127.0.0.1:6379/";
+const MAX_QUEUE_SIZE: usize = 1000;
+const SCHEDULER_TICK: Duration = Duration::from_secs(1);
+
+#[derive(Clone)]
+enum Job {
+    ProcessMeeting { url: String },
+    SyncCity { city_id: String },
+}
+
+struct CitySchedule {
+    interval: Duration,
+    last_run: Instant,
+}
+
+struct SharedState {
+    queue: Mutex<VecDeque<Job>>,
+    not_empty: Condvar,
+    stop: AtomicBool,
+    active_workers: AtomicUsize,
+    jobs_completed: AtomicU64,
+    jobs_failed: AtomicU64,
+    extractor: PdfExtractor,
+    city_schedules: Mutex<HashMap<String, CitySchedule>>,
+}
+
+impl SharedState {
+    fn enqueue(&self, job: Job) -> Result<(), String> {
+        let mut queue = self.queue.lock().unwrap();
+        if queue.len() >= MAX_QUEUE_SIZE {
+            return Err(format!("Queue is full ({} jobs)", MAX_QUEUE_SIZE));
+        }
+        queue.push_back(job);
+        self.not_empty.notify_one();
+        Ok(())
+    }
+}
+
+/// Orchestrates meeting processing: a bounded job queue, a pool of worker
+/// threads that drain it via `PdfExtractor`/`PdfDownloader`, and a
+/// scheduler thread that re-enqueues each city's sync job on its own
+/// interval.
 #[pyclass]
 pub struct Conductor {
-    is_running: bool,
+    shared: Arc<SharedState>,
+    worker_count: usize,
+    workers: Vec<thread::JoinHandle<()>>,
+    scheduler: Option<thread::JoinHandle<()>>,
+    running: bool,
 }
 
 #[pymethods]
 impl Conductor {
     #[new]
     pub fn new() -> Self {
-        Self { is_running: false }
+        // Shared so that every worker's extractor throttles through the
+        // same per-host budget, keeping sync storms against one city's
+        // server polite.
+        let rate_limiter = Arc::new(RateLimiter::new(DEFAULT_REDIS_URL));
+
+        let shared = Arc::new(SharedState {
+            queue: Mutex::new(VecDeque::new()),
+            not_empty: Condvar::new(),
+            stop: AtomicBool::new(true),
+            active_workers: AtomicUsize::new(0),
+            jobs_completed: AtomicU64::new(0),
+            jobs_failed: AtomicU64::new(0),
+            extractor: PdfExtractor::with_rate_limiter(rate_limiter),
+            city_schedules: Mutex::new(HashMap::new()),
+        });
+
+        Self {
+            shared,
+            worker_count: DEFAULT_WORKER_COUNT,
+            workers: Vec::new(),
+            scheduler: None,
+            running: false,
+        }
     }
 
+    /// Spawn the worker pool and the scheduler thread. Safe to call again
+    /// after `stop()`.
     pub fn start(&mut self) {
-        self.is_running = true;
-        tracing::info!("Conductor started (stub implementation)");
+        if self.running {
+            return;
+        }
+
+        self.shared.stop.store(false, Ordering::SeqCst);
+
+        for _ in 0..self.worker_count {
+            let shared = Arc::clone(&self.shared);
+            self.workers.push(thread::spawn(move || worker_loop(shared)));
+        }
+
+        let shared = Arc::clone(&self.shared);
+        self.scheduler = Some(thread::spawn(move || scheduler_loop(shared)));
+
+        self.running = true;
+        tracing::info!("Conductor started with {} workers", self.worker_count);
     }
 
+    /// Signal all workers and the scheduler to drain and exit, then join
+    /// them. Blocks until shutdown completes.
     pub fn stop(&mut self) {
-        self.is_running = false;
+        if !self.running {
+            return;
+        }
+
+        self.shared.stop.store(true, Ordering::SeqCst);
+        self.shared.not_empty.notify_all();
+
+        for handle in self.workers.drain(..) {
+            let _ = handle.join();
+        }
+        if let Some(handle) = self.scheduler.take() {
+            let _ = handle.join();
+        }
+
+        self.running = false;
         tracing::info!("Conductor stopped");
     }
 
     pub fn is_running(&self) -> bool {
-        self.is_running
+        self.running
+    }
+
+    /// Enqueue a single "process meeting URL" job. Errors if the queue is
+    /// at capacity.
+    pub fn enqueue_meeting(&self, url: String) -> PyResult<()> {
+        self.shared
+            .enqueue(Job::ProcessMeeting { url })
+            .map_err(pyo3::exceptions::PyValueError::new_err)
+    }
+
+    /// Register (or update) a recurring "sync city" job. The scheduler
+    /// enqueues it the first time `interval_secs` elapses after this call,
+    /// then every `interval_secs` thereafter.
+    pub fn schedule_city_sync(&self, city_id: String, interval_secs: u64) {
+        let interval = Duration::from_secs(interval_secs.max(1));
+        let mut schedules = self.shared.city_schedules.lock().unwrap();
+        schedules.insert(city_id, CitySchedule {
+            interval,
+            last_run: Instant::now(),
+        });
+    }
+
+    /// Snapshot of queue depth, active workers, and completed/failed job
+    /// counts since this `Conductor` was created.
+    pub fn stats(&self) -> ConductorStats {
+        ConductorStats {
+            queue_depth: self.shared.queue.lock().unwrap().len(),
+            active_workers: self.shared.active_workers.load(Ordering::SeqCst),
+            jobs_completed: self.shared.jobs_completed.load(Ordering::SeqCst),
+            jobs_failed: self.shared.jobs_failed.load(Ordering::SeqCst),
+        }
     }
 }
 
@@ -38,3 +173,146 @@ impl Default for Conductor {
         Self::new()
     }
 }
+
+impl Drop for Conductor {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
+
+#[pyclass]
+#[derive(Clone, Copy, Debug)]
+pub struct ConductorStats {
+    #[pyo3(get)]
+    pub queue_depth: usize,
+    #[pyo3(get)]
+    pub active_workers: usize,
+    #[pyo3(get)]
+    pub jobs_completed: u64,
+    #[pyo3(get)]
+    pub jobs_failed: u64,
+}
+
+fn worker_loop(shared: Arc<SharedState>) {
+    loop {
+        let job = match next_job(&shared) {
+            Some(job) => job,
+            None => return, // stopping and the queue is drained
+        };
+
+        shared.active_workers.fetch_add(1, Ordering::SeqCst);
+        let success = run_job(&shared, &job);
+        shared.active_workers.fetch_sub(1, Ordering::SeqCst);
+
+        if success {
+            shared.jobs_completed.fetch_add(1, Ordering::SeqCst);
+        } else {
+            shared.jobs_failed.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+}
+
+/// Block for the next job, waking periodically to re-check the stop flag
+/// so shutdown doesn't have to wait on a job that never arrives.
+fn next_job(shared: &SharedState) -> Option<Job> {
+    let mut queue = shared.queue.lock().unwrap();
+    loop {
+        if let Some(job) = queue.pop_front() {
+            return Some(job);
+        }
+        if shared.stop.load(Ordering::SeqCst) {
+            return None;
+        }
+        let (guard, _timeout) = shared
+            .not_empty
+            .wait_timeout(queue, Duration::from_millis(500))
+            .unwrap();
+        queue = guard;
+    }
+}
+
+fn run_job(shared: &SharedState, job: &Job) -> bool {
+    match job {
+        Job::ProcessMeeting { url } => match shared.extractor.extract_from_url(url) {
+            Ok(Some(_)) => true,
+            Ok(None) => {
+                tracing::warn!("Meeting job produced no usable text: {}", url);
+                false
+            }
+            Err(e) => {
+                tracing::warn!("Meeting job failed for {}: {}", url, e);
+                false
+            }
+        },
+        Job::SyncCity { city_id } => {
+            // Listing a city's current meetings is still driven from the
+            // Python side; this tick exists so that work shows up in
+            // `stats()` alongside meeting jobs. There's no URL/host to
+            // throttle here yet - once this tick fetches a city's agenda
+            // listing itself, it should do so through `shared.extractor`
+            // like `ProcessMeeting` does, so it shares the same per-host
+            // rate budget instead of acquiring against a fake key.
+            tracing::info!("City sync tick for {}", city_id);
+            true
+        }
+    }
+}
+
+fn scheduler_loop(shared: Arc<SharedState>) {
+    while !shared.stop.load(Ordering::SeqCst) {
+        let due_cities: Vec<String> = {
+            let mut schedules = shared.city_schedules.lock().unwrap();
+            let now = Instant::now();
+            schedules
+                .iter_mut()
+                .filter_map(|(city_id, schedule)| {
+                    if now.duration_since(schedule.last_run) >= schedule.interval {
+                        schedule.last_run = now;
+                        Some(city_id.clone())
+                    } else {
+                        None
+                    }
+                })
+                .collect()
+        };
+
+        for city_id in due_cities {
+            if let Err(e) = shared.enqueue(Job::SyncCity { city_id: city_id.clone() }) {
+                tracing::warn!("Dropped city sync for {}: {}", city_id, e);
+            }
+        }
+
+        thread::sleep(SCHEDULER_TICK);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_start_stop_cycle() {
+        let mut conductor = Conductor::new();
+        assert!(!conductor.is_running());
+
+        conductor.start();
+        assert!(conductor.is_running());
+
+        conductor.stop();
+        assert!(!conductor.is_running());
+    }
+
+    #[test]
+    fn test_enqueue_and_stats() {
+        let mut conductor = Conductor::new();
+        conductor.start();
+
+        conductor.enqueue_meeting("".to_string()).unwrap();
+        thread::sleep(Duration::from_millis(200));
+
+        let stats = conductor.stats();
+        assert_eq!(stats.jobs_completed + stats.jobs_failed, 1);
+
+        conductor.stop();
+    }
+}
@@ -4,19 +4,38 @@ mod pdf;
 mod conductor;
 mod database;
 mod rate_limiter;
+mod reporting;
 
 // Re-export main types
-pub use pdf::{PdfExtractor, PdfExtractionResult};
-pub use conductor::Conductor;
+pub use pdf::{
+    BatchExtractionReport, BatchItemResult, PdfExtractor, PdfExtractionResult, QualityVerdict,
+    TextStats, TextValidator,
+};
+pub use conductor::{Conductor, ConductorStats};
+pub use reporting::{init_logging, ProcessingReport, ReportEntry};
 
 // PyO3 module definition - exposes Rust functions to Python
 #[pymodule]
 fn engagic_core(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    // Logging - call engagic_core.init_logging(...) once at startup to
+    // choose verbosity and an optional log file instead of a fixed subscriber.
+    m.add_function(wrap_pyfunction!(init_logging, m)?)?;
+
     // PDF extraction
     m.add_class::<PdfExtractor>()?;
+    m.add_class::<BatchExtractionReport>()?;
+    m.add_class::<BatchItemResult>()?;
+    m.add_class::<TextValidator>()?;
+    m.add_class::<QualityVerdict>()?;
+    m.add_class::<TextStats>()?;
 
     // Conductor (queue processor, sync loop)
     m.add_class::<Conductor>()?;
+    m.add_class::<ConductorStats>()?;
+
+    // Run-level processing report
+    m.add_class::<ProcessingReport>()?;
+    m.add_class::<ReportEntry>()?;
 
     Ok(())
 }
@@ -1,11 +1,20 @@
 use reqwest::blocking::Client;
+use std::sync::Arc;
 use std::time::Duration;
 use thiserror::Error;
 
+use crate::rate_limiter::RateLimiter;
+
 const MAX_PDF_SIZE: usize = 200 * 1024 * 1024; // 200MB
 const DOWNLOAD_TIMEOUT_SECS: u64 = 30;
 const MAX_RETRIES: usize = 3;
 
+// Conservative per-host rate and burst applied to every download when
+// rate limiting is enabled via `with_rate_limiter`; there is no per-call
+// override.
+const DEFAULT_RATE_PER_SEC: f64 = 1.0;
+const DEFAULT_BURST: f64 = 5.0;
+
 #[derive(Error, Debug)]
 pub enum DownloadError {
     #[error("Network error: {0}")]
@@ -26,6 +35,7 @@ pub struct PdfDownloader {
     max_size: usize,
     timeout: Duration,
     max_retries: usize,
+    rate_limiter: Option<Arc<RateLimiter>>,
 }
 
 impl PdfDownloader {
@@ -41,6 +51,16 @@ impl PdfDownloader {
             max_size: MAX_PDF_SIZE,
             timeout: Duration::from_secs(DOWNLOAD_TIMEOUT_SECS),
             max_retries: MAX_RETRIES,
+            rate_limiter: None,
+        }
+    }
+
+    /// Same as `new()`, but throttles downloads through a shared
+    /// `RateLimiter` keyed on each URL's host before every attempt.
+    pub fn with_rate_limiter(rate_limiter: Arc<RateLimiter>) -> Self {
+        Self {
+            rate_limiter: Some(rate_limiter),
+            ..Self::new()
         }
     }
 
@@ -59,6 +79,7 @@ impl PdfDownloader {
 
         // Retry loop
         for attempt in 1..=self.max_retries {
+            self.throttle(&actual_url);
             match self.download_once(&actual_url) {
                 Ok(bytes) => {
                     tracing::info!(
@@ -93,6 +114,24 @@ impl PdfDownloader {
         }
     }
 
+    /// Block until the shared rate limiter grants a token for `url`'s
+    /// host. No-op when rate limiting isn't configured.
+    fn throttle(&self, url: &str) {
+        let Some(limiter) = &self.rate_limiter else {
+            return;
+        };
+
+        let Some(host) = reqwest::Url::parse(url).ok().and_then(|u| u.host_str().map(str::to_string)) else {
+            return;
+        };
+
+        let wait = limiter.acquire(&host, DEFAULT_RATE_PER_SEC, DEFAULT_BURST);
+        if !wait.is_zero() {
+            tracing::debug!("Rate limited on {}: sleeping {:?}", host, wait);
+            std::thread::sleep(wait);
+        }
+    }
+
     fn download_once(&self, url: &str) -> Result<Vec<u8>, DownloadError> {
         let response = self.client.get(url).send()?;
 
@@ -0,0 +1,144 @@
+// EPUB export for extracted meeting packets, so a resident can read an
+// agenda offline instead of just getting a text blob back. Reuses the
+// same `epub-builder` pattern as paperoni / royal_road_archiver: one
+// XHTML chapter per page, built off the `--- PAGE N ---` markers the
+// extractor already inserts into `all_text`.
+
+use epub_builder::{EpubBuilder, EpubContent, ReferenceType, ZipLibrary};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum EpubBuildError {
+    #[error("EPUB build failed: {0}")]
+    Builder(#[from] epub_builder::Error),
+}
+
+/// Build an EPUB from extracted text, splitting on the `--- PAGE N ---`
+/// markers so the table of contents lets a reader jump straight to a
+/// page. `title` is reinserted as a top-level `<h1>` at the start of the
+/// first chapter.
+pub fn build_epub(text: &str, title: &str) -> Result<Vec<u8>, EpubBuildError> {
+    let pages = split_into_pages(text);
+
+    let mut builder = EpubBuilder::new(ZipLibrary::new()?)?;
+    builder.metadata("title", title)?;
+    builder.metadata("author", "Engagic")?;
+
+    for (index, (page_number, page)) in pages.iter().enumerate() {
+        let heading = if index == 0 {
+            format!(
+                "<h1>{}</h1>\n<h2>Page {}</h2>\n",
+                escape_xml(title),
+                page_number
+            )
+        } else {
+            format!("<h2>Page {}</h2>\n", page_number)
+        };
+
+        let paragraphs = escape_xml(page)
+            .split('\n')
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| format!("<p>{}</p>", line))
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        let xhtml = format!(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+             <!DOCTYPE html>\n\
+             <html xmlns=\"http://www.w3.org/1999/xhtml\"><body>{}{}</body></html>",
+            heading, paragraphs
+        );
+
+        let filename = format!("page_{:04}.xhtml", index + 1);
+        let content = EpubContent::new(filename, xhtml.as_bytes())
+            .title(format!("Page {}", page_number))
+            .reftype(if index == 0 {
+                ReferenceType::TitlePage
+            } else {
+                ReferenceType::Text
+            });
+
+        builder.add_content(content)?;
+    }
+
+    let mut output = Vec::new();
+    builder.generate(&mut output)?;
+    Ok(output)
+}
+
+/// Split extractor output on its `--- PAGE N ---` markers, dropping the
+/// markers themselves and keeping each page's number `N` and body text.
+/// Text with no markers becomes a single page 1.
+fn split_into_pages(text: &str) -> Vec<(usize, String)> {
+    let mut pages = Vec::new();
+    let mut current = String::new();
+    let mut current_number = 1;
+
+    for line in text.lines() {
+        if let Some(number) = line
+            .strip_prefix("--- PAGE ")
+            .and_then(|rest| rest.strip_suffix(" ---"))
+        {
+            if !current.trim().is_empty() {
+                pages.push((current_number, std::mem::take(&mut current)));
+            }
+            current_number = number.trim().parse().unwrap_or(current_number + 1);
+            continue;
+        }
+        current.push_str(line);
+        current.push('\n');
+    }
+
+    if !current.trim().is_empty() {
+        pages.push((current_number, current));
+    }
+
+    if pages.is_empty() {
+        pages.push((1, text.to_string()));
+    }
+
+    pages
+}
+
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_into_pages() {
+        let text = "--- PAGE 1 ---\nHello\n--- PAGE 2 ---\nWorld";
+        let pages = split_into_pages(text);
+        assert_eq!(pages.len(), 2);
+        assert_eq!(pages[0].0, 1);
+        assert!(pages[0].1.contains("Hello"));
+        assert_eq!(pages[1].0, 2);
+        assert!(pages[1].1.contains("World"));
+    }
+
+    #[test]
+    fn test_split_into_pages_no_markers() {
+        let text = "Just plain text, no page markers";
+        let pages = split_into_pages(text);
+        assert_eq!(pages.len(), 1);
+        assert!(pages[0].1.contains("plain text"));
+    }
+
+    #[test]
+    fn test_escape_xml() {
+        assert_eq!(escape_xml("A & B <tag>"), "A &amp; B &lt;tag&gt;");
+    }
+
+    #[test]
+    fn test_build_epub_produces_nonempty_archive() {
+        let text = "--- PAGE 1 ---\nCity council meeting agenda.";
+        let bytes = build_epub(text, "City Council - 2024-06-01").unwrap();
+        // EPUB files are zip archives and start with the local file header magic.
+        assert_eq!(&bytes[0..2], b"PK");
+    }
+}
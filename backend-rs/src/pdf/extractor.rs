@@ -0,0 +1,392 @@
+use pyo3::prelude::*;
+use pyo3::exceptions::PyValueError;
+use poppler::Document;
+use unicode_normalization::UnicodeNormalization;
+use regex::Regex;
+use std::collections::VecDeque;
+use std::fmt;
+use std::sync::{Arc, Mutex};
+
+use super::downloader::PdfDownloader;
+use super::epub_export;
+use super::validator::{QualityVerdict, TextValidator};
+use crate::rate_limiter::RateLimiter;
+
+const MAX_PAGES: usize = 1000;
+const DEFAULT_MAX_CONNECTIONS: usize = 8;
+
+#[pyclass]
+pub struct PdfExtractor {
+    downloader: PdfDownloader,
+    validator: TextValidator,
+    max_pages: usize,
+}
+
+#[pymethods]
+impl PdfExtractor {
+    #[new]
+    pub fn new() -> Self {
+        Self {
+            downloader: PdfDownloader::new(),
+            validator: TextValidator::default(),
+            max_pages: MAX_PAGES,
+        }
+    }
+
+    /// Download and extract text from PDF URL
+    /// Returns (text, page_count) or None if extraction fails
+    pub fn extract_from_url(&self, url: &str) -> PyResult<Option<PdfExtractionResult>> {
+        // Download PDF
+        let pdf_bytes = self.downloader
+            .download(url)
+            .map_err(|e| PyValueError::new_err(format!("Download failed: {}", e)))?;
+
+        // Extract text
+        self.extract_from_bytes(&pdf_bytes)
+    }
+
+    /// Extract text from PDF bytes
+    /// Confidence: 9/10 - poppler handles Identity-H and other complex encodings
+    pub fn extract_from_bytes(&self, pdf_bytes: &[u8]) -> PyResult<Option<PdfExtractionResult>> {
+        // Load PDF document using poppler
+        let document = Document::from_data(pdf_bytes, None)
+            .map_err(|e| PyValueError::new_err(format!("PDF parsing failed: {}", e)))?;
+
+        let page_count = document.n_pages() as usize;
+
+        // Limit pages
+        let pages_to_process = page_count.min(self.max_pages);
+
+        // Extract text from each page
+        let mut all_text = Vec::new();
+
+        for page_num in 0..pages_to_process {
+            match document.page(page_num as i32) {
+                Some(page) => {
+                    if let Some(text) = page.text() {
+                        if !text.is_empty() {
+                            all_text.push(format!("--- PAGE {} ---\n{}", page_num + 1, text));
+                        }
+                    }
+                }
+                None => {
+                    tracing::debug!("Failed to get page {}", page_num + 1);
+                }
+            }
+        }
+
+        if all_text.is_empty() {
+            tracing::warn!("No text extracted from PDF");
+            return Ok(None);
+        }
+
+        let combined_text = all_text.join("\n");
+
+        // Normalize and validate
+        let normalized = normalize_text(&combined_text);
+
+        // Debug: show text preview before validation. Truncate by char
+        // count, not byte offset - a fixed byte slice can land inside a
+        // multi-byte UTF-8 sequence for accented/bilingual civic text.
+        let preview: String = normalized.chars().take(500).collect();
+        tracing::debug!(
+            "Extracted text preview (first 500 chars): {}...",
+            preview.replace('\n', " ")
+        );
+
+        // Validate quality
+        let verdict = self.validator.is_good_quality(&normalized);
+        if !verdict.passed {
+            if verdict.is_likely_scanned() {
+                tracing::warn!(
+                    "Rejected likely-scanned PDF ({}): measured {:.2}, required {:.2} \
+                     - consider routing to OCR instead of discarding",
+                    verdict.failure.as_deref().unwrap_or("unknown"),
+                    verdict.measured,
+                    verdict.required
+                );
+            }
+            return Ok(None);
+        }
+
+        Ok(Some(PdfExtractionResult {
+            text: normalized,
+            page_count,
+            pages_processed: pages_to_process,
+        }))
+    }
+
+    /// Validate text quality without extraction
+    pub fn validate_text(&self, text: &str) -> QualityVerdict {
+        self.validator.is_good_quality(text)
+    }
+
+    /// Download and extract many PDFs with bounded concurrency, returning a
+    /// per-URL breakdown plus aggregate counts so callers can report e.g.
+    /// "42 agendas processed, 3 partial, 1 failed" without looping one URL
+    /// at a time from the Python side.
+    ///
+    /// `max_connections` bounds how many downloads are in flight at once
+    /// (defaults to 8); extraction itself runs on the same worker once its
+    /// download completes, since poppler parsing is comparatively cheap.
+    /// Confidence: 7/10 - thread-pool-over-a-shared-queue is a simple stand-in
+    /// for a real semaphore but gives the same in-flight bound.
+    #[pyo3(signature = (urls, max_connections=None))]
+    pub fn extract_batch(
+        &self,
+        urls: Vec<String>,
+        max_connections: Option<usize>,
+    ) -> PyResult<BatchExtractionReport> {
+        let worker_count = max_connections
+            .unwrap_or(DEFAULT_MAX_CONNECTIONS)
+            .max(1)
+            .min(urls.len().max(1));
+
+        let queue: Mutex<VecDeque<(usize, String)>> = Mutex::new(
+            urls.iter().cloned().enumerate().collect(),
+        );
+        let results: Mutex<Vec<Option<BatchItemResult>>> =
+            Mutex::new((0..urls.len()).map(|_| None).collect());
+
+        std::thread::scope(|scope| {
+            for _ in 0..worker_count {
+                scope.spawn(|| loop {
+                    let next = queue.lock().unwrap().pop_front();
+                    let Some((index, url)) = next else {
+                        break;
+                    };
+
+                    let item = self.extract_one(&url);
+                    results.lock().unwrap()[index] = Some(item);
+                });
+            }
+        });
+
+        let items: Vec<BatchItemResult> = results
+            .into_inner()
+            .unwrap()
+            .into_iter()
+            .map(|item| item.expect("every queued index is filled by a worker"))
+            .collect();
+
+        let mut report = BatchExtractionReport {
+            total: items.len(),
+            successful: 0,
+            partial: 0,
+            failed: 0,
+            items: Vec::new(),
+        };
+        for item in &items {
+            match item.outcome {
+                BatchOutcome::Successful => report.successful += 1,
+                BatchOutcome::Partial => report.partial += 1,
+                BatchOutcome::Failed => report.failed += 1,
+            }
+        }
+        report.items = items;
+
+        tracing::info!(
+            "Batch extraction complete: {} processed, {} successful, {} partial, {} failed",
+            report.total,
+            report.successful,
+            report.partial,
+            report.failed
+        );
+
+        Ok(report)
+    }
+}
+
+impl PdfExtractor {
+    /// Same as `new()`, but threads `rate_limiter` into the underlying
+    /// `PdfDownloader` so every fetch this extractor makes is throttled
+    /// per-host. `Conductor` uses this to share one limiter across all of
+    /// its workers instead of each one hammering city servers unthrottled.
+    pub fn with_rate_limiter(rate_limiter: Arc<RateLimiter>) -> Self {
+        Self {
+            downloader: PdfDownloader::with_rate_limiter(rate_limiter),
+            validator: TextValidator::default(),
+            max_pages: MAX_PAGES,
+        }
+    }
+
+    /// Download and extract a single URL for `extract_batch`, collapsing the
+    /// download/extraction error paths into the successful/partial/failed
+    /// buckets instead of propagating a `PyResult`.
+    fn extract_one(&self, url: &str) -> BatchItemResult {
+        let pdf_bytes = match self.downloader.download(url) {
+            Ok(bytes) => bytes,
+            Err(e) => {
+                return BatchItemResult {
+                    url: url.to_string(),
+                    outcome: BatchOutcome::Failed,
+                    result: None,
+                    error: Some(format!("Download failed: {}", e)),
+                };
+            }
+        };
+
+        match self.extract_from_bytes(&pdf_bytes) {
+            Ok(Some(result)) => BatchItemResult {
+                url: url.to_string(),
+                outcome: BatchOutcome::Successful,
+                result: Some(result),
+                error: None,
+            },
+            Ok(None) => BatchItemResult {
+                url: url.to_string(),
+                outcome: BatchOutcome::Partial,
+                result: None,
+                error: Some("Downloaded but produced no text or failed quality validation".to_string()),
+            },
+            Err(e) => BatchItemResult {
+                url: url.to_string(),
+                outcome: BatchOutcome::Failed,
+                result: None,
+                error: Some(e.to_string()),
+            },
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BatchOutcome {
+    Successful,
+    Partial,
+    Failed,
+}
+
+impl fmt::Display for BatchOutcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            BatchOutcome::Successful => "successful",
+            BatchOutcome::Partial => "partial",
+            BatchOutcome::Failed => "failed",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+#[pyclass]
+#[derive(Clone)]
+pub struct BatchItemResult {
+    #[pyo3(get)]
+    pub url: String,
+    outcome: BatchOutcome,
+    #[pyo3(get)]
+    pub result: Option<PdfExtractionResult>,
+    #[pyo3(get)]
+    pub error: Option<String>,
+}
+
+#[pymethods]
+impl BatchItemResult {
+    #[getter]
+    fn outcome(&self) -> String {
+        self.outcome.to_string()
+    }
+}
+
+#[pyclass]
+pub struct BatchExtractionReport {
+    #[pyo3(get)]
+    pub total: usize,
+    #[pyo3(get)]
+    pub successful: usize,
+    #[pyo3(get)]
+    pub partial: usize,
+    #[pyo3(get)]
+    pub failed: usize,
+    #[pyo3(get)]
+    pub items: Vec<BatchItemResult>,
+}
+
+#[pyclass]
+#[derive(Clone)]
+pub struct PdfExtractionResult {
+    #[pyo3(get)]
+    pub text: String,
+    #[pyo3(get)]
+    pub page_count: usize,
+    #[pyo3(get)]
+    pub pages_processed: usize,
+}
+
+#[pymethods]
+impl PdfExtractionResult {
+    /// Render this result as a navigable EPUB: one chapter per page
+    /// (using the `--- PAGE N ---` markers already present in `text`),
+    /// with `title` reinserted as a top-level heading on the first page.
+    pub fn to_epub(&self, title: &str) -> PyResult<Vec<u8>> {
+        epub_export::build_epub(&self.text, title)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+}
+
+// Normalize extracted text
+fn normalize_text(text: &str) -> String {
+    // Unicode normalization
+    let normalized: String = text.nfc().collect();
+
+    // Remove excessive whitespace
+    let re_newlines = Regex::new(r"\n{3,}").unwrap();
+    let re_spaces = Regex::new(r" {2,}").unwrap();
+
+    let cleaned = re_newlines.replace_all(&normalized, "\n\n");
+    let cleaned = re_spaces.replace_all(&cleaned, " ");
+
+    // Fix common extraction issues
+    let cleaned = cleaned.replace('|', "I");  // Common OCR mistake
+    let cleaned = cleaned.replace('‚', ",");  // Unicode comma issue
+
+    cleaned.trim().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_text() {
+        let input = "Hello   world\n\n\n\nTest";
+        let output = normalize_text(input);
+        assert_eq!(output, "Hello world\n\nTest");
+    }
+
+    #[test]
+    fn test_extractor_creation() {
+        let extractor = PdfExtractor::new();
+        assert_eq!(extractor.max_pages, MAX_PAGES);
+    }
+
+    #[test]
+    fn test_batch_outcome_display() {
+        assert_eq!(BatchOutcome::Successful.to_string(), "successful");
+        assert_eq!(BatchOutcome::Partial.to_string(), "partial");
+        assert_eq!(BatchOutcome::Failed.to_string(), "failed");
+    }
+
+    #[test]
+    fn test_extract_batch_invariant_on_empty_input() {
+        let extractor = PdfExtractor::new();
+        let report = extractor.extract_batch(Vec::new(), None).unwrap();
+        assert_eq!(report.total, 0);
+        assert_eq!(
+            report.total,
+            report.successful + report.partial + report.failed
+        );
+    }
+
+    #[test]
+    fn test_extract_batch_all_failed_invalid_urls() {
+        let extractor = PdfExtractor::new();
+        let urls = vec!["".to_string(), "".to_string()];
+        let report = extractor.extract_batch(urls, Some(2)).unwrap();
+        assert_eq!(report.total, 2);
+        assert_eq!(report.failed, 2);
+        assert_eq!(
+            report.total,
+            report.successful + report.partial + report.failed
+        );
+    }
+}
@@ -0,0 +1,8 @@
+mod downloader;
+mod epub_export;
+mod extractor;
+mod validator;
+
+pub use downloader::{DownloadError, PdfDownloader};
+pub use extractor::{BatchExtractionReport, BatchItemResult, PdfExtractionResult, PdfExtractor};
+pub use validator::{QualityVerdict, TextStats, TextValidator};
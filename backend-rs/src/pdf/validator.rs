@@ -1,5 +1,6 @@
 use std::collections::HashSet;
 use once_cell::sync::Lazy;
+use pyo3::prelude::*;
 
 const MIN_TEXT_LENGTH: usize = 100;
 const MIN_LETTER_RATIO: f32 = 0.3;
@@ -7,8 +8,10 @@ const MIN_WORDS: usize = 20;
 const MIN_RECOGNIZABLE_WORDS: usize = 5;
 const MAX_SINGLE_CHAR_RATIO: f32 = 0.4;
 
-// Common civic/government words for validation
-static CIVIC_WORDS: Lazy<HashSet<&'static str>> = Lazy::new(|| {
+// Default English civic/government words. Deployments that need Spanish
+// civic terms (or any other jurisdiction-specific vocabulary) pass
+// `extra_words` to `TextValidator::new` instead of recompiling.
+static DEFAULT_CIVIC_WORDS: Lazy<HashSet<&'static str>> = Lazy::new(|| {
     [
         // Common words
         "the", "and", "or", "but", "in", "on", "at", "to", "for", "of", "with", "by",
@@ -26,29 +29,53 @@ static CIVIC_WORDS: Lazy<HashSet<&'static str>> = Lazy::new(|| {
     .collect()
 });
 
+#[pyclass]
 pub struct TextValidator {
     min_length: usize,
     min_letter_ratio: f32,
     min_words: usize,
     min_recognizable: usize,
     max_single_char_ratio: f32,
+    recognizable_words: HashSet<String>,
 }
 
+#[pymethods]
 impl TextValidator {
-    pub fn new() -> Self {
+    /// Build a validator with optional overrides of every threshold plus
+    /// an optional list of extra recognizable words (merged with the
+    /// default English civic vocabulary, not replacing it). Any argument
+    /// left as `None` keeps the repo's default tuned for English civic
+    /// documents.
+    #[new]
+    #[pyo3(signature = (min_length=None, min_letter_ratio=None, min_words=None, min_recognizable=None, max_single_char_ratio=None, extra_words=None))]
+    pub fn new(
+        min_length: Option<usize>,
+        min_letter_ratio: Option<f32>,
+        min_words: Option<usize>,
+        min_recognizable: Option<usize>,
+        max_single_char_ratio: Option<f32>,
+        extra_words: Option<Vec<String>>,
+    ) -> Self {
+        let mut recognizable_words: HashSet<String> =
+            DEFAULT_CIVIC_WORDS.iter().map(|w| w.to_string()).collect();
+        if let Some(words) = extra_words {
+            recognizable_words.extend(words.into_iter().map(|w| w.to_lowercase()));
+        }
+
         Self {
-            min_length: MIN_TEXT_LENGTH,
-            min_letter_ratio: MIN_LETTER_RATIO,
-            min_words: MIN_WORDS,
-            min_recognizable: MIN_RECOGNIZABLE_WORDS,
-            max_single_char_ratio: MAX_SINGLE_CHAR_RATIO,
+            min_length: min_length.unwrap_or(MIN_TEXT_LENGTH),
+            min_letter_ratio: min_letter_ratio.unwrap_or(MIN_LETTER_RATIO),
+            min_words: min_words.unwrap_or(MIN_WORDS),
+            min_recognizable: min_recognizable.unwrap_or(MIN_RECOGNIZABLE_WORDS),
+            max_single_char_ratio: max_single_char_ratio.unwrap_or(MAX_SINGLE_CHAR_RATIO),
+            recognizable_words,
         }
     }
 
-    /// Validate text quality
-    /// Returns true if text passes all quality checks
+    /// Validate text quality, returning which check failed (if any) and
+    /// the measured vs. required value, instead of a bare pass/fail.
     /// Confidence: 8/10 - Heuristics work well for civic documents
-    pub fn is_good_quality(&self, text: &str) -> bool {
+    pub fn is_good_quality(&self, text: &str) -> QualityVerdict {
         // Check 1: Minimum length
         if text.len() < self.min_length {
             tracing::debug!(
@@ -56,7 +83,11 @@ impl TextValidator {
                 text.len(),
                 self.min_length
             );
-            return false;
+            return QualityVerdict::fail(
+                QualityFailure::TooShort,
+                text.len() as f32,
+                self.min_length as f32,
+            );
         }
 
         // Check 2: Letter ratio
@@ -65,7 +96,7 @@ impl TextValidator {
 
         if total_chars == 0 {
             tracing::debug!("Quality check FAILED: Zero characters");
-            return false;
+            return QualityVerdict::fail(QualityFailure::TooShort, 0.0, self.min_length as f32);
         }
 
         let letter_ratio = letters as f32 / total_chars as f32;
@@ -75,7 +106,11 @@ impl TextValidator {
                 letter_ratio * 100.0,
                 self.min_letter_ratio * 100.0
             );
-            return false;
+            return QualityVerdict::fail(
+                QualityFailure::LowLetterRatio,
+                letter_ratio,
+                self.min_letter_ratio,
+            );
         }
 
         // Check 3: Word count
@@ -86,19 +121,18 @@ impl TextValidator {
                 words.len(),
                 self.min_words
             );
-            return false;
+            return QualityVerdict::fail(
+                QualityFailure::TooFewWords,
+                words.len() as f32,
+                self.min_words as f32,
+            );
         }
 
         // Check 4: Recognizable words
         let sample_words: Vec<&str> = words.iter().take(100).copied().collect();
         let recognizable = sample_words
             .iter()
-            .filter(|word| {
-                let cleaned = word
-                    .trim_matches(|c: char| !c.is_alphabetic())
-                    .to_lowercase();
-                CIVIC_WORDS.contains(cleaned.as_str())
-            })
+            .filter(|word| self.is_recognizable(word))
             .count();
 
         if sample_words.len() >= 50 && recognizable < self.min_recognizable {
@@ -107,7 +141,11 @@ impl TextValidator {
                 recognizable,
                 sample_words.len()
             );
-            return false;
+            return QualityVerdict::fail(
+                QualityFailure::Unrecognizable,
+                recognizable as f32,
+                self.min_recognizable as f32,
+            );
         }
 
         // Check 5: Excessive single-character words
@@ -122,7 +160,11 @@ impl TextValidator {
                 "Quality check FAILED: Too many single-char words ({:.1}%)",
                 single_char_ratio * 100.0
             );
-            return false;
+            return QualityVerdict::fail(
+                QualityFailure::SingleCharSpam,
+                single_char_ratio,
+                self.max_single_char_ratio,
+            );
         }
 
         tracing::debug!(
@@ -134,7 +176,7 @@ impl TextValidator {
             sample_words.len()
         );
 
-        true
+        QualityVerdict::pass()
     }
 
     pub fn get_stats(&self, text: &str) -> TextStats {
@@ -145,12 +187,7 @@ impl TextValidator {
         let sample_words: Vec<&str> = words.iter().take(100).copied().collect();
         let recognizable = sample_words
             .iter()
-            .filter(|word| {
-                let cleaned = word
-                    .trim_matches(|c: char| !c.is_alphabetic())
-                    .to_lowercase();
-                CIVIC_WORDS.contains(cleaned.as_str())
-            })
+            .filter(|word| self.is_recognizable(word))
             .count();
 
         TextStats {
@@ -167,18 +204,108 @@ impl TextValidator {
     }
 }
 
+impl TextValidator {
+    fn is_recognizable(&self, word: &str) -> bool {
+        let cleaned = word
+            .trim_matches(|c: char| !c.is_alphabetic())
+            .to_lowercase();
+        self.recognizable_words.contains(cleaned.as_str())
+    }
+}
+
 impl Default for TextValidator {
     fn default() -> Self {
-        Self::new()
+        Self::new(None, None, None, None, None, None)
+    }
+}
+
+/// Which quality check rejected the text, matching the order the checks
+/// run in `TextValidator::is_good_quality`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum QualityFailure {
+    TooShort,
+    LowLetterRatio,
+    TooFewWords,
+    Unrecognizable,
+    SingleCharSpam,
+}
+
+impl QualityFailure {
+    fn as_str(self) -> &'static str {
+        match self {
+            QualityFailure::TooShort => "too_short",
+            QualityFailure::LowLetterRatio => "low_letter_ratio",
+            QualityFailure::TooFewWords => "too_few_words",
+            QualityFailure::Unrecognizable => "unrecognizable",
+            QualityFailure::SingleCharSpam => "single_char_spam",
+        }
+    }
+}
+
+/// Result of `TextValidator::is_good_quality`: which check failed (if
+/// any), plus the measured value and the threshold it was checked
+/// against, so callers can log *why* a document was rejected instead of
+/// just discarding it.
+#[pyclass]
+#[derive(Clone, Debug)]
+pub struct QualityVerdict {
+    #[pyo3(get)]
+    pub passed: bool,
+    #[pyo3(get)]
+    pub failure: Option<String>,
+    #[pyo3(get)]
+    pub measured: f32,
+    #[pyo3(get)]
+    pub required: f32,
+}
+
+#[pymethods]
+impl QualityVerdict {
+    /// True when the text wasn't obviously empty or garbage but failed on
+    /// the letter-ratio or recognizable-word checks — the profile a
+    /// scanned-but-not-OCR'd PDF tends to produce. Callers can use this to
+    /// route the document to a downstream OCR step instead of discarding
+    /// it outright.
+    pub fn is_likely_scanned(&self) -> bool {
+        matches!(
+            self.failure.as_deref(),
+            Some("low_letter_ratio") | Some("unrecognizable")
+        )
     }
 }
 
-#[derive(Debug)]
+impl QualityVerdict {
+    fn pass() -> Self {
+        Self {
+            passed: true,
+            failure: None,
+            measured: 0.0,
+            required: 0.0,
+        }
+    }
+
+    fn fail(failure: QualityFailure, measured: f32, required: f32) -> Self {
+        Self {
+            passed: false,
+            failure: Some(failure.as_str().to_string()),
+            measured,
+            required,
+        }
+    }
+}
+
+#[pyclass]
+#[derive(Clone, Debug)]
 pub struct TextStats {
+    #[pyo3(get)]
     pub total_chars: usize,
+    #[pyo3(get)]
     pub letter_count: usize,
+    #[pyo3(get)]
     pub letter_ratio: f32,
+    #[pyo3(get)]
     pub word_count: usize,
+    #[pyo3(get)]
     pub recognizable_words: usize,
 }
 
@@ -188,33 +315,35 @@ mod tests {
 
     #[test]
     fn test_good_quality_text() {
-        let validator = TextValidator::new();
+        let validator = TextValidator::default();
 
         let good_text = "The city council meeting agenda includes discussion of the new zoning ordinance. \
                         The planning commission will review the budget allocation for infrastructure projects. \
                         Public comment is encouraged at the hearing.";
 
-        assert!(validator.is_good_quality(good_text));
+        assert!(validator.is_good_quality(good_text).passed);
     }
 
     #[test]
     fn test_too_short() {
-        let validator = TextValidator::new();
-        let short_text = "Too short";
-        assert!(!validator.is_good_quality(short_text));
+        let validator = TextValidator::default();
+        let verdict = validator.is_good_quality("Too short");
+        assert!(!verdict.passed);
+        assert_eq!(verdict.failure.as_deref(), Some("too_short"));
     }
 
     #[test]
     fn test_gibberish() {
-        let validator = TextValidator::new();
+        let validator = TextValidator::default();
         let gibberish = "xyzabc qwerty asdfgh zxcvbn mnbvcx qweasd zxcasd qwezxc asdzxc qweasdzxc \
                         mnbvcxzasd qwertyzxc asdfghmnb vcxzaqwer tyuiopasdf ghjklzxcv bnmqwert yuiopasdf";
-        assert!(!validator.is_good_quality(gibberish));
+        let verdict = validator.is_good_quality(gibberish);
+        assert!(!verdict.passed);
     }
 
     #[test]
     fn test_stats() {
-        let validator = TextValidator::new();
+        let validator = TextValidator::default();
         let text = "The city council meeting agenda includes discussion";
         let stats = validator.get_stats(text);
 
@@ -222,4 +351,33 @@ mod tests {
         assert!(stats.word_count > 0);
         assert!(stats.letter_ratio > 0.5);
     }
+
+    #[test]
+    fn test_extra_words_allow_custom_vocabulary() {
+        let validator = TextValidator::new(
+            Some(10),
+            Some(0.1),
+            Some(3),
+            Some(1),
+            Some(1.0),
+            Some(vec!["ayuntamiento".to_string(), "reunion".to_string()]),
+        );
+        let spanish_civic = "ayuntamiento reunion publico";
+        assert!(validator.is_recognizable("Ayuntamiento"));
+        assert!(validator.is_good_quality(spanish_civic).passed);
+    }
+
+    #[test]
+    fn test_low_letter_ratio_is_likely_scanned() {
+        let validator = TextValidator::default();
+        // Long enough and word-like, but mostly digits/punctuation so the
+        // letter-ratio check fails first - the scanned-PDF-with-OCR-noise
+        // profile this verdict is meant to flag.
+        let noisy = "12 34 56 78 90 11 22 33 44 55 66 77 88 99 00 12 34 56 78 90 \
+                      12 34 56 78 90 11 22 33 44 55 66 77 88 99 00 12 34 56 78 90";
+        let verdict = validator.is_good_quality(noisy);
+        assert!(!verdict.passed);
+        assert_eq!(verdict.failure.as_deref(), Some("low_letter_ratio"));
+        assert!(verdict.is_likely_scanned());
+    }
 }
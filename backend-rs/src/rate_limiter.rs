@@ -1,22 +1,187 @@
-// TODO: Redis-backed rate limiter
-// This will replace the in-memory rate limiter in Python
-// Benefits:
-// - Persistent across restarts
-// - Shared across multiple instances
-// - Thread-safe
+// Redis-backed rate limiter
+// Replaces the in-memory rate limiter in Python with a per-host token
+// bucket shared across processing instances via Redis, so a fleet of
+// workers all hitting the same city server stay under one shared budget.
+// Falls back to an in-memory bucket when Redis is unreachable, so a
+// single-instance deployment still gets throttled.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum RateLimiterError {
+    #[error("Redis error: {0}")]
+    Redis(#[from] redis::RedisError),
+}
+
+// Refill tokens based on elapsed time, then take one if available, all in
+// a single EVAL so the check-and-decrement is atomic across instances.
+// Without this, two workers could both read "1 token left" and both
+// proceed (the classic TOCTOU race on a naive GET/SET pair).
+//
+// Redis truncates Lua numbers to integers on return, so the wait time is
+// serialized as a string and parsed back on the Rust side to keep
+// sub-second precision.
+const TOKEN_BUCKET_SCRIPT: &str = r#"
+local key = KEYS[1]
+local rate = tonumber(ARGV[1])
+local burst = tonumber(ARGV[2])
+local now = tonumber(ARGV[3])
+
+local tokens = tonumber(redis.call("HGET", key, "tokens"))
+local last_refill = tonumber(redis.call("HGET", key, "last_refill"))
+
+if tokens == nil then tokens = burst end
+if last_refill == nil then last_refill = now end
+
+local elapsed = now - last_refill
+if elapsed < 0 then elapsed = 0 end
+tokens = math.min(burst, tokens + elapsed * rate)
+
+local wait = 0
+if tokens >= 1 then
+    tokens = tokens - 1
+else
+    wait = (1 - tokens) / rate
+end
+
+redis.call("HSET", key, "tokens", tostring(tokens), "last_refill", tostring(now))
+redis.call("EXPIRE", key, 3600)
+
+return tostring(wait)
+"#;
 
 pub struct RateLimiter {
-    // Redis connection will go here
+    redis_client: Option<redis::Client>,
+    fallback: Mutex<HashMap<String, (f64, f64)>>, // host -> (tokens, last_refill)
 }
 
 impl RateLimiter {
-    pub fn new() -> Self {
-        Self {}
+    /// Open a Redis client for `redis_url` without connecting. If the URL
+    /// can't be parsed, construction still succeeds and every `acquire`
+    /// uses in-memory limiting for the life of this instance; otherwise
+    /// each `acquire` connects to Redis and falls back to in-memory
+    /// limiting for that call when Redis is unreachable.
+    pub fn new(redis_url: &str) -> Self {
+        let redis_client = match redis::Client::open(redis_url) {
+            Ok(client) => Some(client),
+            Err(e) => {
+                tracing::warn!(
+                    "RateLimiter: couldn't open Redis client ({}), using in-memory fallback",
+                    e
+                );
+                None
+            }
+        };
+
+        Self {
+            redis_client,
+            fallback: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Request one token for `host`. Returns how long the caller should
+    /// sleep before proceeding (zero if a token was available now).
+    ///
+    /// Confidence: 8/10 - the Lua script keeps multi-instance acquires
+    /// atomic; the in-memory fallback only protects this process.
+    pub fn acquire(&self, host: &str, rate_per_sec: f64, burst: f64) -> Duration {
+        let now = now_secs();
+
+        let Some(client) = &self.redis_client else {
+            return self.acquire_in_memory(host, rate_per_sec, burst, now);
+        };
+
+        match self.acquire_redis(client, host, rate_per_sec, burst, now) {
+            Ok(wait) => wait,
+            Err(e) => {
+                tracing::warn!(
+                    "RateLimiter: Redis acquire failed ({}), falling back to in-memory for this call",
+                    e
+                );
+                self.acquire_in_memory(host, rate_per_sec, burst, now)
+            }
+        }
+    }
+
+    fn acquire_redis(
+        &self,
+        client: &redis::Client,
+        host: &str,
+        rate_per_sec: f64,
+        burst: f64,
+        now: f64,
+    ) -> Result<Duration, RateLimiterError> {
+        let mut conn = client.get_connection()?;
+        let wait: String = redis::Script::new(TOKEN_BUCKET_SCRIPT)
+            .key(format!("ratelimit:{}", host))
+            .arg(rate_per_sec)
+            .arg(burst)
+            .arg(now)
+            .invoke(&mut conn)?;
+
+        let wait_secs: f64 = wait.parse().unwrap_or(0.0);
+        Ok(Duration::from_secs_f64(wait_secs.max(0.0)))
     }
+
+    fn acquire_in_memory(&self, host: &str, rate_per_sec: f64, burst: f64, now: f64) -> Duration {
+        let mut state = self.fallback.lock().unwrap();
+        let entry = state.entry(host.to_string()).or_insert((burst, now));
+
+        let elapsed = (now - entry.1).max(0.0);
+        let mut tokens = (entry.0 + elapsed * rate_per_sec).min(burst);
+
+        let wait = if tokens >= 1.0 {
+            tokens -= 1.0;
+            0.0
+        } else {
+            (1.0 - tokens) / rate_per_sec
+        };
+
+        entry.0 = tokens;
+        entry.1 = now;
+
+        Duration::from_secs_f64(wait)
+    }
+}
+
+fn now_secs() -> f64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs_f64()
 }
 
-impl Default for RateLimiter {
-    fn default() -> Self {
-        Self::new()
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn limiter_without_redis() -> RateLimiter {
+        // Deliberately unreachable host:port so connection attempts fail
+        // and every acquire exercises the in-memory fallback path.
+        RateLimiter::new("redis://127.0.0.1:1")
+    }
+
+    #[test]
+    fn test_fallback_allows_burst_then_throttles() {
+        let limiter = limiter_without_redis();
+
+        for _ in 0..3 {
+            let wait = limiter.acquire("example.com", 1.0, 3.0);
+            assert_eq!(wait, Duration::ZERO);
+        }
+
+        let wait = limiter.acquire("example.com", 1.0, 3.0);
+        assert!(wait > Duration::ZERO);
+    }
+
+    #[test]
+    fn test_fallback_tracks_hosts_independently() {
+        let limiter = limiter_without_redis();
+
+        assert_eq!(limiter.acquire("a.example.com", 1.0, 1.0), Duration::ZERO);
+        assert_eq!(limiter.acquire("b.example.com", 1.0, 1.0), Duration::ZERO);
     }
 }
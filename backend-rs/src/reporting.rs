@@ -0,0 +1,182 @@
+// Run-level logging setup and processing reports, modeled on paperoni's
+// summary/logging work: verbosity and an optional log-to-file path are
+// chosen from Python instead of the previous fixed `fmt()` subscriber, and
+// `ProcessingReport` gives operators a glanceable "what happened this run"
+// view on top of `extract_batch`.
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use std::sync::{Once, OnceLock};
+use tracing_subscriber::EnvFilter;
+
+use crate::pdf::{BatchExtractionReport, TextValidator};
+
+static LOG_INIT: Once = Once::new();
+static LOG_GUARD: OnceLock<tracing_appender::non_blocking::WorkerGuard> = OnceLock::new();
+
+/// Configure the process-wide tracing subscriber. `verbosity` accepts
+/// anything `tracing_subscriber::EnvFilter` understands (e.g. "info",
+/// "debug", "engagic_core=debug,warn"). When `log_file` is set, logs go
+/// there instead of stdout. Only the first call takes effect, matching
+/// the previous `Once`-guarded behavior.
+#[pyfunction]
+#[pyo3(signature = (verbosity="info", log_file=None))]
+pub fn init_logging(verbosity: &str, log_file: Option<&str>) -> PyResult<()> {
+    let mut result = Ok(());
+    LOG_INIT.call_once(|| {
+        result = configure_logging(verbosity, log_file);
+    });
+    result.map_err(PyValueError::new_err)
+}
+
+fn configure_logging(verbosity: &str, log_file: Option<&str>) -> Result<(), String> {
+    let filter = EnvFilter::try_new(verbosity)
+        .map_err(|e| format!("Invalid verbosity '{}': {}", verbosity, e))?;
+
+    let builder = tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_target(false)
+        .with_thread_ids(false)
+        .with_file(false)
+        .with_line_number(false);
+
+    match log_file {
+        Some(path) => {
+            let file = std::fs::OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(path)
+                .map_err(|e| format!("Couldn't open log file '{}': {}", path, e))?;
+            let (non_blocking, guard) = tracing_appender::non_blocking(file);
+            // Leak the guard into a OnceLock for the process lifetime so
+            // the non-blocking writer doesn't drop its flush thread as
+            // soon as this function returns.
+            let _ = LOG_GUARD.set(guard);
+            builder.with_writer(non_blocking).with_ansi(false).init();
+        }
+        None => builder.init(),
+    }
+
+    Ok(())
+}
+
+/// One processed URL's row in a `ProcessingReport`.
+#[pyclass]
+#[derive(Clone)]
+pub struct ReportEntry {
+    #[pyo3(get)]
+    pub url: String,
+    #[pyo3(get)]
+    pub page_count: usize,
+    #[pyo3(get)]
+    pub word_count: usize,
+    #[pyo3(get)]
+    pub letter_ratio: f32,
+    #[pyo3(get)]
+    pub passed_quality: bool,
+}
+
+/// Accumulates per-URL outcomes across one or more `extract_batch` calls
+/// so a run can be summarized as a table (for a human) or a list of
+/// records (for a dashboard).
+#[pyclass]
+pub struct ProcessingReport {
+    entries: Vec<ReportEntry>,
+}
+
+#[pymethods]
+impl ProcessingReport {
+    #[new]
+    pub fn new() -> Self {
+        Self { entries: Vec::new() }
+    }
+
+    /// Append one row per item in `batch`, pulling page count plus
+    /// word/letter-ratio stats from `TextValidator::get_stats` for items
+    /// that produced text; partial/failed items have no text to measure
+    /// and are recorded with zeroed stats and `passed_quality = false`.
+    pub fn record_batch(&mut self, batch: &BatchExtractionReport) {
+        let validator = TextValidator::default();
+
+        for item in &batch.items {
+            let (page_count, word_count, letter_ratio, passed_quality) = match &item.result {
+                Some(result) => {
+                    let stats = validator.get_stats(&result.text);
+                    (result.page_count, stats.word_count, stats.letter_ratio, true)
+                }
+                None => (0, 0, 0.0, false),
+            };
+
+            self.entries.push(ReportEntry {
+                url: item.url.clone(),
+                page_count,
+                word_count,
+                letter_ratio,
+                passed_quality,
+            });
+        }
+    }
+
+    /// Render a human-readable summary table of every recorded entry.
+    pub fn render_table(&self) -> String {
+        let mut table = comfy_table::Table::new();
+        table.set_header(vec!["URL", "Pages", "Words", "Letter %", "Verdict"]);
+
+        for entry in &self.entries {
+            table.add_row(vec![
+                entry.url.clone(),
+                entry.page_count.to_string(),
+                entry.word_count.to_string(),
+                format!("{:.1}%", entry.letter_ratio * 100.0),
+                (if entry.passed_quality { "PASS" } else { "FAIL" }).to_string(),
+            ]);
+        }
+
+        table.to_string()
+    }
+
+    /// Machine-readable form: one `ReportEntry` per processed URL, for
+    /// callers that want to ship this to a dashboard instead of printing
+    /// it.
+    pub fn to_records(&self) -> Vec<ReportEntry> {
+        self.entries.clone()
+    }
+}
+
+impl Default for ProcessingReport {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pdf::PdfExtractor;
+
+    #[test]
+    fn test_record_batch_and_render_table() {
+        let extractor = PdfExtractor::new();
+        let batch = extractor.extract_batch(vec!["".to_string()], None).unwrap();
+
+        let mut report = ProcessingReport::new();
+        report.record_batch(&batch);
+
+        let records = report.to_records();
+        assert_eq!(records.len(), 1);
+        assert!(!records[0].passed_quality);
+
+        let table = report.render_table();
+        assert!(table.contains("FAIL"));
+    }
+
+    #[test]
+    fn test_empty_report_renders_header_only() {
+        let report = ProcessingReport::new();
+        assert!(report.to_records().is_empty());
+        // A header-only table still renders, with no data rows.
+        let table = report.render_table();
+        assert!(table.contains("Verdict"));
+        assert!(!table.contains("PASS") && !table.contains("FAIL"));
+    }
+}